@@ -0,0 +1,50 @@
+use std::fmt;
+
+/// Errors that can occur while talking to a Nominatim server.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying HTTP request failed (connection, timeout, or reading the
+    /// body). A non-success HTTP status is not reported here; its body is
+    /// decoded like any other response.
+    Http(reqwest::Error),
+    /// The server returned a Nominatim-level error object.
+    Api { code: u32, message: String },
+    /// The response body could not be decoded into the expected shape.
+    Decode(serde_json::Error),
+    /// A request header (identification or `Accept-Language`) could not be
+    /// constructed.
+    InvalidHeader(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(err) => write!(f, "http error: {err}"),
+            Error::Api { code, message } => write!(f, "nominatim error {code}: {message}"),
+            Error::Decode(err) => write!(f, "failed to decode response: {err}"),
+            Error::InvalidHeader(message) => write!(f, "invalid request header: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(err) => Some(err),
+            Error::Decode(err) => Some(err),
+            Error::Api { .. } | Error::InvalidHeader(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Decode(err)
+    }
+}
@@ -1,15 +1,26 @@
 #![doc = include_str!("../README.md")]
 
-use std::{str::FromStr, time::Duration};
+use std::{
+    future::Future,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
+use geo_types::{coord, Point, Rect};
+use geocoding::{Forward, GeocodingError, Reverse};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+mod error;
 mod ident;
 
+pub use error::Error;
 pub use ident::IdentificationMethod;
 
+use serde::de::DeserializeOwned;
+
 /// The interface for accessing a Nominatim API server.
 #[derive(Debug, Clone)]
 pub struct Client {
@@ -18,6 +29,16 @@ pub struct Client {
     client: reqwest::Client,
     /// HTTP Request Timeout [`Duration`]
     pub timeout: Duration,
+    /// Minimum interval between outgoing requests. Defaults to one second to
+    /// honor the `nominatim.openstreetmap.org` usage policy.
+    pub min_interval: Duration,
+    // Instant of the last dispatched request, shared across clones so that
+    // concurrent tasks are serialized to `min_interval`.
+    last_request: Arc<tokio::sync::Mutex<Option<Instant>>>,
+    // Remaining calls parsed from the server's rate-limit headers, if any.
+    remaining_calls: Arc<Mutex<Option<u64>>>,
+    // Default `Accept-Language` applied to search/reverse/lookup requests.
+    language: Option<Language>,
 }
 
 impl Client {
@@ -33,6 +54,108 @@ impl Client {
                 .build()
                 .unwrap(),
             timeout,
+            min_interval: Duration::from_secs(1),
+            last_request: Arc::new(tokio::sync::Mutex::new(None)),
+            remaining_calls: Arc::new(Mutex::new(None)),
+            language: None,
+        }
+    }
+
+    /// Set a default [`Language`] applied to every `search`/`reverse`/`lookup`
+    /// request, sent as both the `accept-language` parameter and HTTP header.
+    ///
+    /// A per-request language overrides it via [`SearchQuery::language`],
+    /// [`ReverseQuery::language`], or [`LookupQuery::language`].
+    pub fn set_language(&mut self, language: impl Into<Language>) {
+        self.language = Some(language.into());
+    }
+
+    /// Number of requests the server reports as remaining before the next
+    /// rate-limit reset, parsed from the response headers.
+    ///
+    /// Returns `None` until a request has been made, and for self-hosted
+    /// servers that do not emit rate-limit headers.
+    pub fn remaining_calls(&self) -> Option<u64> {
+        *self.remaining_calls.lock().unwrap()
+    }
+
+    /// Block until at least [`min_interval`](Self::min_interval) has elapsed
+    /// since the previous request, then mark the current instant.
+    async fn throttle(&self) {
+        let mut last = self.last_request.lock().await;
+
+        if let Some(previous) = *last {
+            let elapsed = previous.elapsed();
+            if elapsed < self.min_interval {
+                tokio::time::sleep(self.min_interval - elapsed).await;
+            }
+        }
+
+        *last = Some(Instant::now());
+    }
+
+    /// Build the identification [`HeaderMap`] sent with every request.
+    fn headers(&self) -> Result<HeaderMap, Error> {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            HeaderName::from_str(&self.ident.header())
+                .map_err(|err| Error::InvalidHeader(err.to_string()))?,
+            HeaderValue::from_str(&self.ident.value())
+                .map_err(|err| Error::InvalidHeader(err.to_string()))?,
+        );
+
+        Ok(headers)
+    }
+
+    /// Apply the effective [`Language`] — the per-call override if present,
+    /// otherwise the client default — as both the `accept-language` query
+    /// parameter and HTTP header.
+    fn apply_language(
+        &self,
+        url: &mut Url,
+        headers: &mut HeaderMap,
+        per_call: Option<&Language>,
+    ) -> Result<(), Error> {
+        if let Some(language) = per_call.or(self.language.as_ref()) {
+            url.query_pairs_mut()
+                .append_pair("accept-language", language.as_str());
+            headers.append(
+                HeaderName::from_static("accept-language"),
+                HeaderValue::from_str(language.as_str())
+                    .map_err(|err| Error::InvalidHeader(err.to_string()))?,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Decode a response body, surfacing a Nominatim error object as
+    /// [`Error::Api`] before attempting to deserialize into `T`.
+    async fn decode<T: DeserializeOwned>(&self, response: reqwest::Response) -> Result<T, Error> {
+        self.record_rate_limit(response.headers());
+
+        let body = response.bytes().await?;
+
+        if let Ok(envelope) = serde_json::from_slice::<ApiError>(&body) {
+            let (code, message) = match envelope.error {
+                ApiErrorBody::Message(message) => (0, message),
+                ApiErrorBody::Detailed { code, message } => (code, message),
+            };
+            return Err(Error::Api { code, message });
+        }
+
+        serde_json::from_slice(&body).map_err(Error::Decode)
+    }
+
+    /// Record the rate-limit headers (if present) from a server response.
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        let remaining = headers
+            .get("x-ratelimit-remaining")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok());
+
+        if remaining.is_some() {
+            *self.remaining_calls.lock().unwrap() = remaining;
         }
     }
 
@@ -54,25 +177,23 @@ impl Client {
     /// assert_eq!(client.status().await.unwrap().message, "OK");
     /// # })
     /// ```
-    pub async fn status(&self) -> Result<Status, reqwest::Error> {
+    pub async fn status(&self) -> Result<Status, Error> {
         let mut url = self.base_url.join("status.php").unwrap();
         url.set_query(Some("format=json"));
 
-        let mut headers = HeaderMap::new();
-        headers.append(
-            HeaderName::from_str(&self.ident.header()).expect("invalid nominatim auth header name"),
-            HeaderValue::from_str(&self.ident.value())
-                .expect("invalid nominatim auth header value"),
-        );
+        let headers = self.headers()?;
+
+        self.throttle().await;
 
-        self.client
+        let response = self
+            .client
             .get(url)
             .headers(headers)
             .timeout(self.timeout)
             .send()
-            .await?
-            .json()
-            .await
+            .await?;
+
+        self.decode(response).await
     }
 
     /// Get [`Place`]s from a search query.
@@ -87,41 +208,100 @@ impl Client {
     /// assert_eq!(client.search("statue of liberty").await.unwrap().len(), 4);
     /// # })
     /// ```
-    pub async fn search(&self, query: impl Into<String>) -> Result<Vec<Place>, reqwest::Error> {
+    pub async fn search(&self, query: impl Into<SearchQuery>) -> Result<Vec<Place>, Error> {
+        let query = query.into();
+
         let mut url = self.base_url.clone();
-        url.set_query(Some(&format!(
-            "addressdetails=1&extratags=1&q={}&format=json",
-            query.into().replace(' ', "+")
-        )));
+        url.set_query(Some("addressdetails=1&extratags=1"));
+        query.append_to(&mut url);
+        url.query_pairs_mut()
+            .append_pair("format", query.format.unwrap_or_default().as_str());
 
-        let mut headers = HeaderMap::new();
-        headers.append(
-            HeaderName::from_str(&self.ident.header()).expect("invalid nominatim auth header name"),
-            HeaderValue::from_str(&self.ident.value())
-                .expect("invalid nominatim auth header value"),
-        );
+        let mut headers = self.headers()?;
+        self.apply_language(&mut url, &mut headers, query.language.as_ref())?;
+
+        self.throttle().await;
 
-        self.client
+        let response = self
+            .client
             .get(url)
             .headers(headers)
             .timeout(self.timeout)
             .send()
-            .await?
-            .json()
-            .await
+            .await?;
+
+        self.decode(response).await
+    }
+
+    /// Get a typed GeoJSON [`FeatureCollection`] from a search query.
+    ///
+    /// Forces `format=geojson`, so callers get the actual feature geometry
+    /// rather than the bounding-box-only [`Place`] list returned by
+    /// [`search`](Self::search). Combine with
+    /// [`SearchQuery::polygon_threshold`] to simplify the returned shapes.
+    pub async fn search_geojson(
+        &self,
+        query: impl Into<SearchQuery>,
+    ) -> Result<FeatureCollection, Error> {
+        self.search_feature_collection(query, "geojson").await
+    }
+
+    /// Get a typed GeocodeJSON [`FeatureCollection`] from a search query.
+    ///
+    /// Like [`search_geojson`](Self::search_geojson) but forces
+    /// `format=geocodejson`; the geocoding details are carried in each
+    /// [`Feature::properties`].
+    pub async fn search_geocodejson(
+        &self,
+        query: impl Into<SearchQuery>,
+    ) -> Result<FeatureCollection, Error> {
+        self.search_feature_collection(query, "geocodejson").await
+    }
+
+    /// Shared implementation for the object-shaped `geojson`/`geocodejson`
+    /// response formats.
+    async fn search_feature_collection(
+        &self,
+        query: impl Into<SearchQuery>,
+        format: &str,
+    ) -> Result<FeatureCollection, Error> {
+        let query = query.into();
+
+        let mut url = self.base_url.clone();
+        url.set_query(Some("addressdetails=1&extratags=1"));
+        query.append_to(&mut url);
+        url.query_pairs_mut().append_pair("format", format);
+
+        let mut headers = self.headers()?;
+        self.apply_language(&mut url, &mut headers, query.language.as_ref())?;
+
+        self.throttle().await;
+
+        let response = self
+            .client
+            .get(url)
+            .headers(headers)
+            .timeout(self.timeout)
+            .send()
+            .await?;
+
+        self.decode(response).await
     }
 
     /// Generate a [`Place`] from latitude and longitude.
     ///
+    /// Pass [`ReverseQuery::default()`] for the previous behavior, or build one
+    /// to request a zoom level, polygon geometry, or a localized language.
+    ///
     /// ```
-    /// # use tag_nominatim::{Client, IdentificationMethod};
+    /// # use tag_nominatim::{Client, IdentificationMethod, ReverseQuery};
     ///
     /// let client = Client::new(IdentificationMethod::from_user_agent(
     ///     "Example Application Name",
     /// ));
     /// # tokio_test::block_on(async {
     /// assert_eq!(
-    ///     client.reverse("40.689249", "-74.044500", None).await.unwrap().display_name,
+    ///     client.reverse("40.689249", "-74.044500", ReverseQuery::default()).await.unwrap().display_name,
     ///     "Statue of Liberty, Flagpole Plaza, Manhattan Community Board 1, Manhattan, New York County, City of New York, New York, 10004, United States"
     /// );
     /// # })
@@ -130,56 +310,74 @@ impl Client {
         &self,
         latitude: impl Into<String>,
         longitude: impl Into<String>,
-        zoom: Option<u8>,
-    ) -> Result<Place, reqwest::Error> {
+        query: impl Into<ReverseQuery>,
+    ) -> Result<Place, Error> {
+        let query = query.into();
+
         let mut url = self.base_url.join("reverse").unwrap();
+        url.set_query(Some("addressdetails=1&extratags=1&format=json"));
+        url.query_pairs_mut()
+            .append_pair("lat", latitude.into().trim())
+            .append_pair("lon", longitude.into().trim());
+        query.append_to(&mut url);
 
-        match zoom {
-            Some(zoom) => {
-                url.set_query(Some(&format!(
-                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}&zoom={}",
-                    latitude.into().replace(' ', ""),
-                    longitude.into().replace(' ', ""),
-                    zoom
-                )));
-            }
-            None => {
-                url.set_query(Some(&format!(
-                    "addressdetails=1&extratags=1&format=json&lat={}&lon={}",
-                    latitude.into().replace(' ', ""),
-                    longitude.into().replace(' ', ""),
-                )));
-            }
-        }
+        let mut headers = self.headers()?;
+        self.apply_language(&mut url, &mut headers, query.language.as_ref())?;
 
-        let mut headers = HeaderMap::new();
-        headers.append(
-            HeaderName::from_str(&self.ident.header()).expect("invalid nominatim auth header name"),
-            HeaderValue::from_str(&self.ident.value())
-                .expect("invalid nominatim auth header value"),
-        );
+        self.throttle().await;
 
-        self.client
+        let response = self
+            .client
             .get(url)
             .headers(headers)
             .timeout(self.timeout)
             .send()
-            .await?
-            .json()
+            .await?;
+
+        self.decode(response).await
+    }
+
+    /// Reverse-geocode a [`geo_types::Point`], using the `geo` convention of
+    /// `x = longitude`, `y = latitude`.
+    ///
+    /// ```
+    /// # use tag_nominatim::{Client, IdentificationMethod, ReverseQuery};
+    /// # use geo_types::Point;
+    ///
+    /// let client = Client::new(IdentificationMethod::from_user_agent(
+    ///     "Example Application Name",
+    /// ));
+    /// # tokio_test::block_on(async {
+    /// let point = Point::new(-74.044500, 40.689249);
+    /// assert_eq!(
+    ///     client.reverse_point(point, ReverseQuery::default()).await.unwrap().display_name,
+    ///     "Statue of Liberty, Flagpole Plaza, Manhattan Community Board 1, Manhattan, New York County, City of New York, New York, 10004, United States"
+    /// );
+    /// # })
+    /// ```
+    pub async fn reverse_point(
+        &self,
+        point: Point<f64>,
+        query: impl Into<ReverseQuery>,
+    ) -> Result<Place, Error> {
+        self.reverse(point.y().to_string(), point.x().to_string(), query)
             .await
     }
 
     /// Return [`Place`]s from a list of OSM Node, Way, or Relations.
     ///
+    /// Pass [`LookupQuery::default()`] for the previous behavior, or build one
+    /// to request polygon geometry or a localized language.
+    ///
     /// ```
-    /// # use tag_nominatim::{Client, IdentificationMethod};
+    /// # use tag_nominatim::{Client, IdentificationMethod, LookupQuery};
     ///
     /// let client = Client::new(IdentificationMethod::from_user_agent(
     ///     "Example Application Name",
     /// ));
     /// # tokio_test::block_on(async {
     /// assert_eq!(
-    ///     client.lookup(vec!["R146656", "W50637691"]).await.unwrap().first().unwrap().display_name,
+    ///     client.lookup(vec!["R146656", "W50637691"], LookupQuery::default()).await.unwrap().first().unwrap().display_name,
     ///     "Manchester, Greater Manchester, England, United Kingdom"
     /// );
     /// # })
@@ -187,7 +385,10 @@ impl Client {
     pub async fn lookup(
         &self,
         queries: Vec<impl Into<String>>,
-    ) -> Result<Vec<Place>, reqwest::Error> {
+        query: impl Into<LookupQuery>,
+    ) -> Result<Vec<Place>, Error> {
+        let query = query.into();
+
         let queries: String = queries
             .into_iter()
             .map(Into::<String>::into)
@@ -195,29 +396,552 @@ impl Client {
             .join(",");
 
         let mut url = self.base_url.join("lookup").unwrap();
-        url.set_query(Some(&format!(
-            "osm_ids={}&addressdetails=1&extratags=1&format=json",
-            queries
-        )));
+        url.set_query(Some("addressdetails=1&extratags=1&format=json"));
+        url.query_pairs_mut().append_pair("osm_ids", &queries);
+        query.append_to(&mut url);
 
-        let mut headers = HeaderMap::new();
-        headers.append(
-            HeaderName::from_str(&self.ident.header()).expect("invalid nominatim auth header name"),
-            HeaderValue::from_str(&self.ident.value())
-                .expect("invalid nominatim auth header value"),
-        );
+        let mut headers = self.headers()?;
+        self.apply_language(&mut url, &mut headers, query.language.as_ref())?;
+
+        self.throttle().await;
 
-        self.client
+        let response = self
+            .client
             .get(url)
             .headers(headers)
             .timeout(self.timeout)
             .send()
-            .await?
-            .json()
-            .await
+            .await?;
+
+        self.decode(response).await
     }
 }
 
+/// A search query for [`Client::search`].
+///
+/// A query is either a single free-form `q` string or a structured address
+/// built from individual components, optionally narrowed by refinement
+/// parameters such as [`limit`](SearchQuery::limit) or
+/// [`countrycodes`](SearchQuery::countrycodes). A bare `&str`/`String`
+/// converts into a free-form query, so `client.search("statue of liberty")`
+/// keeps working.
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    q: Option<String>,
+    street: Option<String>,
+    city: Option<String>,
+    county: Option<String>,
+    state: Option<String>,
+    postalcode: Option<String>,
+    country: Option<String>,
+    limit: Option<u32>,
+    countrycodes: Vec<String>,
+    viewbox: Option<[f64; 4]>,
+    bounded: Option<bool>,
+    dedupe: Option<bool>,
+    exclude_place_ids: Vec<usize>,
+    format: Option<Format>,
+    polygon: Option<PolygonFormat>,
+    polygon_threshold: Option<f64>,
+    language: Option<Language>,
+}
+
+impl SearchQuery {
+    /// Create a free-form query from a single search string.
+    pub fn new(q: impl Into<String>) -> Self {
+        Self {
+            q: Some(q.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Start a structured-address query whose components are set with the
+    /// builder methods below.
+    pub fn structured() -> Self {
+        Self::default()
+    }
+
+    /// Set the `street` component (see [`Street`] for the `<number> <name>`
+    /// helper).
+    pub fn street(mut self, street: impl Into<String>) -> Self {
+        self.street = Some(street.into());
+        self
+    }
+
+    /// Set the `city` component.
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Set the `county` component.
+    pub fn county(mut self, county: impl Into<String>) -> Self {
+        self.county = Some(county.into());
+        self
+    }
+
+    /// Set the `state` component.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Set the `postalcode` component.
+    pub fn postalcode(mut self, postalcode: impl Into<String>) -> Self {
+        self.postalcode = Some(postalcode.into());
+        self
+    }
+
+    /// Set the `country` component.
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Limit the number of returned results.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Restrict results to the given ISO 3166-1 alpha-2 country codes.
+    pub fn countrycodes<I, S>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.countrycodes = codes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Prefer results within the `[left, top, right, bottom]` longitude/latitude
+    /// viewbox.
+    pub fn viewbox(mut self, viewbox: [f64; 4]) -> Self {
+        self.viewbox = Some(viewbox);
+        self
+    }
+
+    /// When `true`, restrict results strictly to the [`viewbox`](Self::viewbox).
+    pub fn bounded(mut self, bounded: bool) -> Self {
+        self.bounded = Some(bounded);
+        self
+    }
+
+    /// Toggle Nominatim's deduplication of near-identical results.
+    pub fn dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = Some(dedupe);
+        self
+    }
+
+    /// Exclude the given place ids from the results.
+    pub fn exclude_place_ids<I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.exclude_place_ids = ids.into_iter().collect();
+        self
+    }
+
+    /// Select the response [`Format`]. Defaults to [`Format::Json`].
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Request polygon geometry output in the given [`PolygonFormat`].
+    pub fn polygon(mut self, polygon: PolygonFormat) -> Self {
+        self.polygon = Some(polygon);
+        self
+    }
+
+    /// Simplification tolerance applied to returned polygon geometry.
+    pub fn polygon_threshold(mut self, threshold: f64) -> Self {
+        self.polygon_threshold = Some(threshold);
+        self
+    }
+
+    /// Request localized result names, overriding the client default [`Language`].
+    pub fn language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Append this query's parameters onto an already-initialized request url.
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+
+        if let Some(q) = &self.q {
+            pairs.append_pair("q", q);
+        }
+        if let Some(street) = &self.street {
+            pairs.append_pair("street", street);
+        }
+        if let Some(city) = &self.city {
+            pairs.append_pair("city", city);
+        }
+        if let Some(county) = &self.county {
+            pairs.append_pair("county", county);
+        }
+        if let Some(state) = &self.state {
+            pairs.append_pair("state", state);
+        }
+        if let Some(postalcode) = &self.postalcode {
+            pairs.append_pair("postalcode", postalcode);
+        }
+        if let Some(country) = &self.country {
+            pairs.append_pair("country", country);
+        }
+        if let Some(limit) = self.limit {
+            pairs.append_pair("limit", &limit.to_string());
+        }
+        if !self.countrycodes.is_empty() {
+            pairs.append_pair("countrycodes", &self.countrycodes.join(","));
+        }
+        if let Some([left, top, right, bottom]) = self.viewbox {
+            pairs.append_pair("viewbox", &format!("{left},{top},{right},{bottom}"));
+        }
+        if let Some(bounded) = self.bounded {
+            pairs.append_pair("bounded", if bounded { "1" } else { "0" });
+        }
+        if let Some(dedupe) = self.dedupe {
+            pairs.append_pair("dedupe", if dedupe { "1" } else { "0" });
+        }
+        if !self.exclude_place_ids.is_empty() {
+            let ids = self
+                .exclude_place_ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            pairs.append_pair("exclude_place_ids", &ids);
+        }
+        if let Some(polygon) = self.polygon {
+            pairs.append_pair(polygon.as_str(), "1");
+        }
+        if let Some(threshold) = self.polygon_threshold {
+            pairs.append_pair("polygon_threshold", &threshold.to_string());
+        }
+    }
+}
+
+/// The response format requested from the server.
+///
+/// Only the two formats that deserialize into [`Place`] are selectable here;
+/// the object-shaped `geojson`/`geocodejson` formats are served by the typed
+/// [`Client::search_geojson`] path instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// The default `json` format.
+    #[default]
+    Json,
+    /// The richer `jsonv2` format.
+    JsonV2,
+}
+
+impl Format {
+    /// The query-string value for this format.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::JsonV2 => "jsonv2",
+        }
+    }
+}
+
+/// The polygon geometry output requested from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonFormat {
+    /// GeoJSON geometry (`polygon_geojson`).
+    GeoJson,
+    /// KML geometry (`polygon_kml`).
+    Kml,
+    /// SVG geometry (`polygon_svg`).
+    Svg,
+    /// WKT-style text geometry (`polygon_text`).
+    Text,
+}
+
+impl PolygonFormat {
+    /// The query-string parameter name for this polygon format.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PolygonFormat::GeoJson => "polygon_geojson",
+            PolygonFormat::Kml => "polygon_kml",
+            PolygonFormat::Svg => "polygon_svg",
+            PolygonFormat::Text => "polygon_text",
+        }
+    }
+}
+
+/// Per-request options for [`Client::reverse`] and [`Client::reverse_point`].
+#[derive(Debug, Clone, Default)]
+pub struct ReverseQuery {
+    zoom: Option<u8>,
+    polygon: Option<PolygonFormat>,
+    polygon_threshold: Option<f64>,
+    language: Option<Language>,
+}
+
+impl ReverseQuery {
+    /// Start an empty set of reverse options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the address-detail zoom level (0–18).
+    pub fn zoom(mut self, zoom: u8) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// Request polygon geometry output. [`PolygonFormat::GeoJson`] output is
+    /// returned in [`Place::geojson`]; the other formats are not kept in [`Place`].
+    pub fn polygon(mut self, polygon: PolygonFormat) -> Self {
+        self.polygon = Some(polygon);
+        self
+    }
+
+    /// Simplification tolerance applied to returned polygon geometry.
+    pub fn polygon_threshold(mut self, threshold: f64) -> Self {
+        self.polygon_threshold = Some(threshold);
+        self
+    }
+
+    /// Request localized result names, overriding the client default [`Language`].
+    pub fn language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Append these options onto an already-initialized request url.
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+
+        if let Some(zoom) = self.zoom {
+            pairs.append_pair("zoom", &zoom.to_string());
+        }
+        if let Some(polygon) = self.polygon {
+            pairs.append_pair(polygon.as_str(), "1");
+        }
+        if let Some(threshold) = self.polygon_threshold {
+            pairs.append_pair("polygon_threshold", &threshold.to_string());
+        }
+    }
+}
+
+impl From<u8> for ReverseQuery {
+    fn from(zoom: u8) -> Self {
+        Self::new().zoom(zoom)
+    }
+}
+
+/// Per-request options for [`Client::lookup`].
+#[derive(Debug, Clone, Default)]
+pub struct LookupQuery {
+    polygon: Option<PolygonFormat>,
+    polygon_threshold: Option<f64>,
+    language: Option<Language>,
+}
+
+impl LookupQuery {
+    /// Start an empty set of lookup options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request polygon geometry output. [`PolygonFormat::GeoJson`] output is
+    /// returned in [`Place::geojson`]; the other formats are not kept in [`Place`].
+    pub fn polygon(mut self, polygon: PolygonFormat) -> Self {
+        self.polygon = Some(polygon);
+        self
+    }
+
+    /// Simplification tolerance applied to returned polygon geometry.
+    pub fn polygon_threshold(mut self, threshold: f64) -> Self {
+        self.polygon_threshold = Some(threshold);
+        self
+    }
+
+    /// Request localized result names, overriding the client default [`Language`].
+    pub fn language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Append these options onto an already-initialized request url.
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+
+        if let Some(polygon) = self.polygon {
+            pairs.append_pair(polygon.as_str(), "1");
+        }
+        if let Some(threshold) = self.polygon_threshold {
+            pairs.append_pair("polygon_threshold", &threshold.to_string());
+        }
+    }
+}
+
+/// An `Accept-Language` value: a single BCP-47 tag (`"de"`) or an
+/// RFC-2616 priority list (`["de", "en"]`), serialized comma-separated.
+#[derive(Debug, Clone)]
+pub struct Language(String);
+
+impl Language {
+    /// The comma-separated language string sent to the server.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Language {
+    fn from(tag: &str) -> Self {
+        Language(tag.to_string())
+    }
+}
+
+impl From<String> for Language {
+    fn from(tag: String) -> Self {
+        Language(tag)
+    }
+}
+
+impl From<&[&str]> for Language {
+    fn from(tags: &[&str]) -> Self {
+        Language(tags.join(","))
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Language {
+    fn from(tags: [&str; N]) -> Self {
+        Language(tags.join(","))
+    }
+}
+
+impl<const N: usize> From<&[&str; N]> for Language {
+    fn from(tags: &[&str; N]) -> Self {
+        Language(tags.join(","))
+    }
+}
+
+impl From<&str> for SearchQuery {
+    fn from(q: &str) -> Self {
+        Self::new(q)
+    }
+}
+
+impl From<String> for SearchQuery {
+    fn from(q: String) -> Self {
+        Self::new(q)
+    }
+}
+
+/// A structured street component of a [`SearchQuery`], serialized as
+/// `"<house_number> <street_name>"`.
+#[derive(Debug, Clone)]
+pub struct Street {
+    pub house_number: String,
+    pub street_name: String,
+}
+
+impl Street {
+    /// Create a [`Street`] from a house number and street name.
+    pub fn new(house_number: impl Into<String>, street_name: impl Into<String>) -> Self {
+        Self {
+            house_number: house_number.into(),
+            street_name: street_name.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Street {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.house_number, self.street_name)
+    }
+}
+
+impl From<Street> for String {
+    fn from(street: Street) -> Self {
+        street.to_string()
+    }
+}
+
+/// Map a crate [`Error`] onto the `geocoding` crate's error type, keeping the
+/// underlying transport error where possible and reporting the direction
+/// (forward vs reverse) the failure occurred in.
+fn geocoding_error(err: Error, direction: GeocodingError) -> GeocodingError {
+    match err {
+        Error::Http(err) => GeocodingError::Request(err),
+        _ => direction,
+    }
+}
+
+/// Drive an async request to completion from the synchronous `geocoding`
+/// traits.
+///
+/// The future is run on a dedicated current-thread runtime on a scoped thread,
+/// so this works whether or not the caller is already inside a Tokio runtime —
+/// the naive `Runtime::new().block_on(..)` would panic with "Cannot start a
+/// runtime from within a runtime" in an otherwise-async app. `direction`
+/// selects which [`GeocodingError`] variant represents a runtime failure.
+fn block_on_blocking<F>(future: F, direction: fn() -> GeocodingError) -> Result<F::Output, GeocodingError>
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map(|runtime| runtime.block_on(future))
+            })
+            .join()
+            .map_err(|_| direction())?
+            .map_err(|_| direction())
+    })
+}
+
+impl Forward<f64> for Client {
+    fn forward(&self, place: &str) -> Result<Vec<Point<f64>>, GeocodingError> {
+        let places = block_on_blocking(self.search(place), || GeocodingError::Forward)?
+            .map_err(|err| geocoding_error(err, GeocodingError::Forward))?;
+
+        Ok(places.iter().filter_map(Place::point).collect())
+    }
+}
+
+impl Reverse<f64> for Client {
+    fn reverse(&self, point: &Point<f64>) -> Result<Option<String>, GeocodingError> {
+        let place = block_on_blocking(
+            self.reverse_point(*point, ReverseQuery::default()),
+            || GeocodingError::Reverse,
+        )?
+        .map_err(|err| geocoding_error(err, GeocodingError::Reverse))?;
+
+        Ok(Some(place.display_name))
+    }
+}
+
+/// The error envelope a Nominatim server returns on bad input. Depending on
+/// the endpoint this is either `{"error": "message"}` (e.g. `reverse`) or the
+/// detailed `{"error": {"code": .., "message": ..}}` shape.
+#[derive(Deserialize)]
+struct ApiError {
+    error: ApiErrorBody,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ApiErrorBody {
+    Message(String),
+    Detailed {
+        #[serde(default)]
+        code: u32,
+        message: String,
+    },
+}
+
 /// The status of a Nominatim server.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Status {
@@ -255,6 +979,68 @@ pub struct Place {
     #[serde(default)]
     pub address: Option<Address>,
     pub extratags: Option<ExtraTags>,
+    /// Polygon geometry, present when a [`PolygonFormat::GeoJson`] output was
+    /// requested.
+    #[serde(default)]
+    pub geojson: Option<Geometry>,
+}
+
+impl Place {
+    /// The place's coordinate as a [`geo_types::Point`], using the `geo`
+    /// convention of `x = longitude`, `y = latitude`.
+    ///
+    /// Returns `None` if either coordinate is missing or unparseable.
+    pub fn point(&self) -> Option<Point<f64>> {
+        let lon = self.lon.parse::<f64>().ok()?;
+        let lat = self.lat.parse::<f64>().ok()?;
+
+        Some(Point::new(lon, lat))
+    }
+
+    /// The place's bounding box as a [`geo_types::Rect`].
+    ///
+    /// Nominatim reports the box as `[min_lat, max_lat, min_lon, max_lon]`;
+    /// this is returned with `x = longitude`, `y = latitude`.
+    pub fn bounding_box(&self) -> Option<Rect<f64>> {
+        let [min_lat, max_lat, min_lon, max_lon] = match self.boundingbox.as_slice() {
+            [a, b, c, d] => [a, b, c, d],
+            _ => return None,
+        };
+
+        Some(Rect::new(
+            coord! { x: min_lon.parse::<f64>().ok()?, y: min_lat.parse::<f64>().ok()? },
+            coord! { x: max_lon.parse::<f64>().ok()?, y: max_lat.parse::<f64>().ok()? },
+        ))
+    }
+}
+
+/// GeoJSON geometry (as returned under the `geojson` key) for a [`Place`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Geometry {
+    #[serde(rename = "type")]
+    pub geometry_type: String,
+    pub coordinates: serde_json::Value,
+}
+
+/// A GeoJSON `FeatureCollection`, returned by [`Client::search_geojson`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeatureCollection {
+    #[serde(rename = "type")]
+    pub collection_type: String,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+
+/// A single GeoJSON `Feature` within a [`FeatureCollection`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Feature {
+    #[serde(rename = "type")]
+    pub feature_type: String,
+    pub geometry: Option<Geometry>,
+    #[serde(default)]
+    pub properties: serde_json::Value,
+    #[serde(default)]
+    pub bbox: Option<Vec<f64>>,
 }
 
 /// An address for a place.
@@ -279,3 +1065,99 @@ pub struct ExtraTags {
     pub wikipedia: Option<String>,
     pub population: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+    use url::Url;
+
+    fn search_pairs(query: &SearchQuery) -> Vec<(String, String)> {
+        let mut url = Url::parse("https://example.com/").unwrap();
+        query.append_to(&mut url);
+        url.query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn free_form_query_serializes_q() {
+        assert_eq!(
+            search_pairs(&SearchQuery::new("statue of liberty")),
+            vec![("q".to_string(), "statue of liberty".to_string())]
+        );
+    }
+
+    #[test]
+    fn structured_query_serializes_components_in_order() {
+        let query = SearchQuery::structured()
+            .street(Street::new("221B", "Baker Street"))
+            .city("Berlin")
+            .postalcode("10115")
+            .limit(5)
+            .countrycodes(["de", "at"])
+            .viewbox([13.0, 52.6, 13.8, 52.3])
+            .bounded(true);
+
+        assert_eq!(
+            search_pairs(&query),
+            vec![
+                ("street".to_string(), "221B Baker Street".to_string()),
+                ("city".to_string(), "Berlin".to_string()),
+                ("postalcode".to_string(), "10115".to_string()),
+                ("limit".to_string(), "5".to_string()),
+                ("countrycodes".to_string(), "de,at".to_string()),
+                ("viewbox".to_string(), "13,52.6,13.8,52.3".to_string()),
+                ("bounded".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn street_joins_number_and_name() {
+        let street = Street::new("221B", "Baker Street");
+        assert_eq!(street.to_string(), "221B Baker Street");
+        assert_eq!(String::from(street), "221B Baker Street");
+    }
+
+    #[test]
+    fn language_joins_tags() {
+        assert_eq!(Language::from("de").as_str(), "de");
+        assert_eq!(Language::from(["de", "en"]).as_str(), "de,en");
+        assert_eq!(Language::from(&["de", "en"]).as_str(), "de,en");
+        assert_eq!(Language::from(&["de", "en"][..]).as_str(), "de,en");
+    }
+
+    #[test]
+    fn bounding_box_uses_lon_lat_order() {
+        let place: Place = serde_json::from_value(serde_json::json!({
+            "boundingbox": ["52.3", "52.6", "13.0", "13.8"],
+            "lat": "52.5",
+            "lon": "13.4",
+        }))
+        .unwrap();
+
+        let rect = place.bounding_box().unwrap();
+        assert_eq!(rect.min().x, 13.0);
+        assert_eq!(rect.min().y, 52.3);
+        assert_eq!(rect.max().x, 13.8);
+        assert_eq!(rect.max().y, 52.6);
+
+        let point = place.point().unwrap();
+        assert_eq!(point.x(), 13.4);
+        assert_eq!(point.y(), 52.5);
+    }
+
+    #[test]
+    fn throttle_enforces_min_interval() {
+        tokio_test::block_on(async {
+            let mut client = Client::new(IdentificationMethod::from_user_agent("test"));
+            client.min_interval = Duration::from_millis(50);
+
+            let start = Instant::now();
+            client.throttle().await; // first request dispatches immediately
+            client.throttle().await; // second waits out the interval
+            assert!(start.elapsed() >= Duration::from_millis(50));
+        });
+    }
+}